@@ -9,7 +9,7 @@
 //!
 //! ## Features
 //!
-//! - Maps common HTTP status codes (300–511) to well-defined application-specific errors.
+//! - Maps common HTTP status codes (100–511) to well-defined application-specific errors.
 //! - Integrates seamlessly with the `cdumay_error` ecosystem.
 //! - Allows contextual error data and custom messages.
 //! - Supports conversion from both `u16` and `http::StatusCode`.
@@ -63,10 +63,22 @@ use std::collections::BTreeMap;
 /// - 4xx: Client Errors
 /// - 5xx: Server Errors
 define_kinds! {
+    // Informational (1xx)
+    Continue = ("HTTP-09211", 100, "Continue"),
+    SwitchingProtocols = ("HTTP-24478", 101, "Switching Protocols"),
+    EarlyHints = ("HTTP-16603", 103, "Early Hints"),
+
+    // Success (2xx)
+    HttpOk = ("HTTP-30215", 200, "OK"),
+    Created = ("HTTP-02968", 201, "Created"),
+    Accepted = ("HTTP-21047", 202, "Accepted"),
+    NoContent = ("HTTP-13586", 204, "No Content"),
+    PartialContent = ("HTTP-17042", 206, "Partial Content"),
+
     // Redirection (3xx)
     MultipleChoices = ("HTTP-11298", 300, "Multiple Choices"),
     MovedPermanently = ("HTTP-23108", 301, "Moved Permanently"),
-    Found = ("HTTP-07132", 302, "HttpRedirection302"),
+    Found = ("HTTP-07132", 302, "Found"),
     SeeOther = ("HTTP-16746", 303, "See Other"),
     NotModified = ("HTTP-21556", 304, "Not Modified"),
     UseProxy = ("HTTP-31839", 305, "Use Proxy"),
@@ -75,16 +87,16 @@ define_kinds! {
 
     // Client Errors (4xx)
     BadRequest = ("HTTP-26760", 400, "Bad Request"),
-    Unauthorized = ("HTTP-08059", 401, "HttpClientError401"),
+    Unauthorized = ("HTTP-08059", 401, "Unauthorized"),
     PaymentRequired = ("HTTP-18076", 402, "Payment Required"),
-    Forbidden = ("HTTP-23134", 403, "HttpClientError403"),
-    NotFound = ("HTTP-18430", 404, "Not HttpRedirection302"),
+    Forbidden = ("HTTP-23134", 403, "Forbidden"),
+    NotFound = ("HTTP-18430", 404, "Not Found"),
     MethodNotAllowed = ("HTTP-23585", 405, "Method Not Allowed"),
     NotAcceptable = ("HTTP-04289", 406, "Not Acceptable"),
     ProxyAuthenticationRequired = ("HTTP-17336", 407, "Proxy Authentication Required"),
     RequestTimeout = ("HTTP-00565", 408, "Request Timeout"),
-    Conflict = ("HTTP-08442", 409, "HttpClientError409"),
-    Gone = ("HTTP-19916", 410, "HttpClientError410"),
+    Conflict = ("HTTP-08442", 409, "Conflict"),
+    Gone = ("HTTP-19916", 410, "Gone"),
     LengthRequired = ("HTTP-09400", 411, "Length Required"),
     PreconditionFailed = ("HTTP-22509", 412, "Precondition Failed"),
     PayloadTooLarge = ("HTTP-10591", 413, "Payload Too Large"),
@@ -95,7 +107,7 @@ define_kinds! {
     ImATeapot = ("HTTP-23719", 418, "I'm a teapot"),
     MisdirectedRequest = ("HTTP-26981", 421, "Misdirected Request"),
     UnprocessableEntity = ("HTTP-12568", 422, "Unprocessable Entity"),
-    Locked = ("HTTP-32695", 423, "HttpClientError423"),
+    Locked = ("HTTP-32695", 423, "Locked"),
     FailedDependency = ("HTTP-19693", 424, "Failed Dependency"),
     UpgradeRequired = ("HTTP-22991", 426, "Upgrade Required"),
     PreconditionRequired = ("HTTP-02452", 428, "Precondition Required"),
@@ -115,12 +127,32 @@ define_kinds! {
     LoopDetected = ("HTTP-30770", 508, "Loop Detected"),
     NotExtended = ("HTTP-19347", 510, "Not Extended"),
     NetworkAuthenticationRequired = ("HTTP-31948", 511, "Network Authentication Required"),
+
+    // Transport/network errors: these happen before any HTTP status code exists (DNS, TLS,
+    // connection, protocol-level failures), so they're given their own 590-599 code block
+    // instead of colliding with a real status. `RequestTimeout` above already covers the
+    // server-returned 408; `TransportRequestTimeout` is its socket-level counterpart.
+    HostLookupFailed = ("HTTP-05521", 590, "Host Lookup Failed"),
+    ConnectionFailed = ("HTTP-14832", 591, "Connection Failed"),
+    TransportRequestTimeout = ("HTTP-27391", 592, "Request Timeout"),
+    BadServerCertificate = ("HTTP-06284", 593, "Bad Server Certificate"),
+    BadClientCertificate = ("HTTP-18765", 594, "Bad Client Certificate"),
+    InvalidCredentials = ("HTTP-29440", 595, "Invalid Credentials"),
+    ProtocolViolation = ("HTTP-03157", 596, "Protocol Violation"),
 }
 
 /// Maps error kinds to usable error types.
 ///
 /// These types can be constructed and used in code and tests.
 define_errors! {
+    HttpInformational100 = Continue,
+    HttpInformational101 = SwitchingProtocols,
+    HttpInformational103 = EarlyHints,
+    HttpSuccess200 = HttpOk,
+    HttpSuccess201 = Created,
+    HttpSuccess202 = Accepted,
+    HttpSuccess204 = NoContent,
+    HttpSuccess206 = PartialContent,
     HttpRedirection300 = MultipleChoices,
     HttpRedirection301 = MovedPermanently,
     HttpRedirection302 = Found,
@@ -168,6 +200,34 @@ define_errors! {
     HttpServerError508 = LoopDetected,
     HttpServerError510 = NotExtended,
     HttpServerError511 = NetworkAuthenticationRequired,
+
+    HttpTransportErrorHostLookupFailed = HostLookupFailed,
+    HttpTransportErrorConnectionFailed = ConnectionFailed,
+    HttpTransportErrorRequestTimeout = TransportRequestTimeout,
+    HttpTransportErrorBadServerCertificate = BadServerCertificate,
+    HttpTransportErrorBadClientCertificate = BadClientCertificate,
+    HttpTransportErrorInvalidCredentials = InvalidCredentials,
+    HttpTransportErrorProtocolViolation = ProtocolViolation,
+}
+
+/// Selects which transport/network-level failure [`HTTPErrorConverter::from_transport`] should
+/// build, for failures that happen before any HTTP status code exists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransportErrorKind {
+    /// DNS resolution failed for the target host.
+    HostLookupFailed,
+    /// The underlying connection could not be established (e.g. connection refused).
+    ConnectionFailed,
+    /// The request timed out at the socket level, before a response was received.
+    RequestTimeout,
+    /// The server presented a TLS certificate the client rejected.
+    BadServerCertificate,
+    /// The server rejected the client's TLS certificate.
+    BadClientCertificate,
+    /// Authentication at the transport layer (e.g. TLS client auth, proxy auth) failed.
+    InvalidCredentials,
+    /// The peer violated the wire protocol (e.g. malformed HTTP framing).
+    ProtocolViolation,
 }
 
 /// Converts HTTP status codes into `cdumay_error::Error` objects.
@@ -192,6 +252,14 @@ impl HTTPErrorConverter {
     /// Unknown status codes will fall back to `HttpServerError500`.
     pub fn from_u16(status: u16, text: Option<String>, context: BTreeMap<String, serde_value::Value>) -> Error {
         let mut error = match status {
+            100 => Error::from(HttpInformational100::new().set_details(context)),
+            101 => Error::from(HttpInformational101::new().set_details(context)),
+            103 => Error::from(HttpInformational103::new().set_details(context)),
+            200 => Error::from(HttpSuccess200::new().set_details(context)),
+            201 => Error::from(HttpSuccess201::new().set_details(context)),
+            202 => Error::from(HttpSuccess202::new().set_details(context)),
+            204 => Error::from(HttpSuccess204::new().set_details(context)),
+            206 => Error::from(HttpSuccess206::new().set_details(context)),
             300 => Error::from(HttpRedirection300::new().set_details(context)),
             301 => Error::from(HttpRedirection301::new().set_details(context)),
             302 => Error::from(HttpRedirection302::new().set_details(context)),
@@ -242,10 +310,76 @@ impl HTTPErrorConverter {
         };
         if let Some(txt) = text {
             error.message = txt;
+        } else if let Some(reason) = Self::canonical_reason(status) {
+            error.message = reason.to_string();
         }
         error
     }
 
+    /// Returns the standard HTTP reason phrase for `code`, for every status this crate knows
+    /// about, and `None` for any other code (including the 590-599 transport codes). This is
+    /// what [`Self::from_u16`] defaults a missing `text` to.
+    pub fn canonical_reason(code: u16) -> Option<&'static str> {
+        Some(match code {
+            100 => "Continue",
+            101 => "Switching Protocols",
+            103 => "Early Hints",
+            200 => "OK",
+            201 => "Created",
+            202 => "Accepted",
+            204 => "No Content",
+            206 => "Partial Content",
+            300 => "Multiple Choices",
+            301 => "Moved Permanently",
+            302 => "Found",
+            303 => "See Other",
+            304 => "Not Modified",
+            305 => "Use Proxy",
+            307 => "Temporary Redirect",
+            308 => "Permanent Redirect",
+            400 => "Bad Request",
+            401 => "Unauthorized",
+            402 => "Payment Required",
+            403 => "Forbidden",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            406 => "Not Acceptable",
+            407 => "Proxy Authentication Required",
+            408 => "Request Timeout",
+            409 => "Conflict",
+            410 => "Gone",
+            411 => "Length Required",
+            412 => "Precondition Failed",
+            413 => "Payload Too Large",
+            414 => "URI Too Long",
+            415 => "Unsupported Media Type",
+            416 => "Range Not Satisfiable",
+            417 => "Expectation Failed",
+            418 => "I'm a teapot",
+            421 => "Misdirected Request",
+            422 => "Unprocessable Entity",
+            423 => "Locked",
+            424 => "Failed Dependency",
+            426 => "Upgrade Required",
+            428 => "Precondition Required",
+            429 => "Too Many Requests",
+            431 => "Request Header Fields Too Large",
+            451 => "Unavailable For Legal Reasons",
+            500 => "Internal Server Error",
+            501 => "Not Implemented",
+            502 => "Bad Gateway",
+            503 => "Service Unavailable",
+            504 => "Gateway Timeout",
+            505 => "HTTP Version Not Supported",
+            506 => "Variant Also Negotiates",
+            507 => "Insufficient Storage",
+            508 => "Loop Detected",
+            510 => "Not Extended",
+            511 => "Network Authentication Required",
+            _ => return None,
+        })
+    }
+
     /// Converts a `http::StatusCode` into a structured `Error`.
     ///
     /// This is a convenience wrapper around [`Self::from_u16`] for working with the
@@ -253,4 +387,226 @@ impl HTTPErrorConverter {
     pub fn from_status(status: StatusCode, text: Option<String>, context: BTreeMap<String, serde_value::Value>) -> Error {
         Self::from_u16(status.as_u16(), text, context)
     }
+
+    /// Converts a response's status and headers into a structured `Error`, the same as
+    /// [`Self::from_status`] but additionally capturing headers that carry information callers
+    /// need to implement backoff or follow redirects, without re-parsing the original response:
+    ///
+    /// - For `429` and `503`, the `Retry-After` header (delta-seconds or HTTP-date) is parsed
+    ///   into a `retry_after_secs` detail.
+    /// - For `3xx`, the `Location` header is copied into a `location` detail.
+    pub fn from_response(status: StatusCode, headers: &http::HeaderMap, text: Option<String>, mut context: BTreeMap<String, serde_value::Value>) -> Error {
+        match status.as_u16() {
+            429 | 503 => {
+                if let Some(secs) = headers.get(http::header::RETRY_AFTER).and_then(|value| value.to_str().ok()).and_then(Self::parse_retry_after) {
+                    context.insert("retry_after_secs".to_string(), serde_value::Value::U64(secs));
+                }
+            }
+            300..=399 => {
+                if let Some(location) = headers.get(http::header::LOCATION).and_then(|value| value.to_str().ok()) {
+                    context.insert("location".to_string(), serde_value::Value::String(location.to_string()));
+                }
+            }
+            _ => {}
+        }
+        Self::from_status(status, text, context)
+    }
+
+    /// Parses a `Retry-After` header value, accepting both the delta-seconds form (e.g. `"120"`)
+    /// and the HTTP-date form (e.g. `"Fri, 31 Dec 1999 23:59:59 GMT"`).
+    fn parse_retry_after(value: &str) -> Option<u64> {
+        let value = value.trim();
+        if let Ok(secs) = value.parse::<u64>() {
+            return Some(secs);
+        }
+        let target = httpdate::parse_http_date(value).ok()?;
+        target.duration_since(std::time::SystemTime::now()).ok().map(|duration| duration.as_secs())
+    }
+
+    /// Converts a transport/network-level failure into a structured `Error`.
+    ///
+    /// Use this instead of [`Self::from_u16`]/[`Self::from_status`] for failures that happen
+    /// before any response is received: DNS, TLS, connection, and protocol-level errors.
+    pub fn from_transport(kind: TransportErrorKind, text: Option<String>, context: BTreeMap<String, serde_value::Value>) -> Error {
+        let mut error = match kind {
+            TransportErrorKind::HostLookupFailed => Error::from(HttpTransportErrorHostLookupFailed::new().set_details(context)),
+            TransportErrorKind::ConnectionFailed => Error::from(HttpTransportErrorConnectionFailed::new().set_details(context)),
+            TransportErrorKind::RequestTimeout => Error::from(HttpTransportErrorRequestTimeout::new().set_details(context)),
+            TransportErrorKind::BadServerCertificate => Error::from(HttpTransportErrorBadServerCertificate::new().set_details(context)),
+            TransportErrorKind::BadClientCertificate => Error::from(HttpTransportErrorBadClientCertificate::new().set_details(context)),
+            TransportErrorKind::InvalidCredentials => Error::from(HttpTransportErrorInvalidCredentials::new().set_details(context)),
+            TransportErrorKind::ProtocolViolation => Error::from(HttpTransportErrorProtocolViolation::new().set_details(context)),
+        };
+        if let Some(txt) = text {
+            error.message = txt;
+        }
+        error
+    }
+
+    /// Renders `error` as an RFC 7807 `application/problem+json` document.
+    ///
+    /// See [`ProblemDetails`] for how the error's fields map onto the standard members.
+    pub fn to_problem_json(error: &Error) -> String {
+        serde_json::to_string(&ProblemDetails::from(error)).expect("ProblemDetails always serializes")
+    }
+
+    /// Renders `error` in the given `format`, returning the body and its content type.
+    ///
+    /// This is the content-negotiated counterpart of [`Self::to_problem_json`]: use
+    /// [`Self::negotiate_format`] to pick `format` from an incoming `Accept` header.
+    pub fn render(error: &Error, format: ErrorFormat) -> (String, &'static str) {
+        match format {
+            ErrorFormat::Json => (
+                serde_json::json!({
+                    "error": {
+                        "code": error.kind.code(),
+                        "message_id": error.kind.message_id(),
+                        "message": error.message,
+                        "details": error.details,
+                    }
+                })
+                .to_string(),
+                "application/json",
+            ),
+            ErrorFormat::Text => (format!("code:{}\nmessage_id:{}\nmessage:{}", error.kind.code(), error.kind.message_id(), error.message), "text/plain; charset=utf-8"),
+            ErrorFormat::Html => (
+                format!(
+                    "<!DOCTYPE html><html><head><title>{code} {title}</title></head><body><h1>{title}</h1><p>{message}</p></body></html>",
+                    code = error.kind.code(),
+                    title = Self::html_escape(error.kind.description()),
+                    message = Self::html_escape(&error.message),
+                ),
+                "text/html; charset=utf-8",
+            ),
+        }
+    }
+
+    /// Escapes `&`, `<`, `>`, and `"` so untrusted text (e.g. a message sourced from an upstream
+    /// response body) can be safely interpolated into the HTML body produced by [`Self::render`].
+    fn html_escape(text: &str) -> String {
+        text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+    }
+
+    /// Parses an `Accept` header value and picks the best matching [`ErrorFormat`], honoring
+    /// `q` weights. Falls back to [`ErrorFormat::Json`] when nothing recognized is present.
+    pub fn negotiate_format(accept: &str) -> ErrorFormat {
+        accept
+            .split(',')
+            .filter_map(|part| {
+                let mut segments = part.split(';');
+                let media_type = segments.next().unwrap_or("").trim();
+                let quality = segments.find_map(|param| param.trim().strip_prefix("q=")).and_then(|q| q.trim().parse::<f32>().ok()).unwrap_or(1.0);
+                let format = match media_type {
+                    "text/html" | "application/xhtml+xml" => Some(ErrorFormat::Html),
+                    "text/plain" => Some(ErrorFormat::Text),
+                    "application/json" | "application/problem+json" | "*/*" => Some(ErrorFormat::Json),
+                    _ => None,
+                };
+                format.map(|format| (quality, format))
+            })
+            .max_by(|(qa, _), (qb, _)| qa.partial_cmp(qb).unwrap_or(std::cmp::Ordering::Equal))
+            .map(|(_, format)| format)
+            .unwrap_or(ErrorFormat::Json)
+    }
+}
+
+/// Output formats supported by [`HTTPErrorConverter::render`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorFormat {
+    /// `{"error":{"code":..,"message_id":..,"message":..,"details":..}}`
+    Json,
+    /// A small templated HTML page with the code, the kind description, and the message.
+    Html,
+    /// `code:..\nmessage_id:..\nmessage:..`
+    Text,
+}
+
+/// An RFC 7807 "Problem Details for HTTP APIs" document built from a [`cdumay_error::Error`].
+///
+/// The standard members `type`, `title`, `status` and `detail` are always present; `instance` is
+/// omitted when it is `None`, which is what the conversions from an `Error` leave it. The error's `details` context is flattened into the document as extension members, per the
+/// spec's requirement that unrecognized members be ignored by consumers. A context key that
+/// collides with a reserved member name is kept under a `context_`-prefixed key instead of
+/// overwriting it.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ProblemDetails {
+    pub r#type: String,
+    pub title: String,
+    pub status: u16,
+    pub detail: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    #[serde(flatten)]
+    pub extensions: BTreeMap<String, serde_value::Value>,
+}
+
+impl ProblemDetails {
+    /// Standard members that extension keys from the error's context must not overwrite.
+    const RESERVED_MEMBERS: [&'static str; 5] = ["type", "title", "status", "detail", "instance"];
+
+    /// Builds a `ProblemDetails` document from `error`, qualifying the `type` URI by joining
+    /// `base_url` with the error's `message_id`. With `base_url` set to `None`, `type` defaults
+    /// to `about:blank`, per the spec's recommendation for problems without a registered URI.
+    pub fn with_type_base(error: &Error, base_url: Option<&str>) -> Self {
+        let r#type = match base_url {
+            Some(base) => format!("{}/{}", base.trim_end_matches('/'), error.kind.message_id()),
+            None => "about:blank".to_string(),
+        };
+
+        let mut extensions = BTreeMap::new();
+        if let Some(details) = &error.details {
+            for (key, value) in details {
+                if Self::RESERVED_MEMBERS.contains(&key.as_str()) {
+                    extensions.insert(format!("context_{key}"), value.clone());
+                } else {
+                    extensions.insert(key.clone(), value.clone());
+                }
+            }
+        }
+
+        let status = error.kind.code();
+        let title = HTTPErrorConverter::canonical_reason(status).map(str::to_string).unwrap_or_else(|| error.kind.description().to_string());
+
+        ProblemDetails { r#type, title, status, detail: error.message.clone(), instance: None, extensions }
+    }
+}
+
+impl From<&Error> for ProblemDetails {
+    /// Builds a `ProblemDetails` document from `error`, defaulting `type` to `about:blank`.
+    fn from(error: &Error) -> Self {
+        Self::with_type_base(error, None)
+    }
+}
+
+/// Renders an error as an HTTP response, the inverse of [`HTTPErrorConverter`]'s status-to-error
+/// mapping. Implementing this lets an error type sit on the response side of a handler, not just
+/// on the side that parses upstream responses.
+pub trait WebResponseError {
+    /// The HTTP status this error should be rendered with. Kind codes that aren't a valid HTTP
+    /// status (out of range, unknown, or one of this crate's synthetic 590-599 transport codes,
+    /// which were never a real status to begin with) map to `500 Internal Server Error`.
+    fn status_code(&self) -> StatusCode;
+
+    /// Builds the full HTTP response: the status from [`Self::status_code`] and an
+    /// `application/problem+json` body produced via [`HTTPErrorConverter::to_problem_json`].
+    fn error_response(&self) -> http::Response<String>;
+}
+
+impl WebResponseError for Error {
+    fn status_code(&self) -> StatusCode {
+        let code = self.kind.code();
+        if (590..=599).contains(&code) {
+            return StatusCode::INTERNAL_SERVER_ERROR;
+        }
+        StatusCode::from_u16(code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    fn error_response(&self) -> http::Response<String> {
+        let body = HTTPErrorConverter::to_problem_json(self);
+        http::Response::builder()
+            .status(self.status_code())
+            .header(http::header::CONTENT_TYPE, "application/problem+json")
+            .body(body.clone())
+            .unwrap_or_else(|_| http::Response::new(body))
+    }
 }
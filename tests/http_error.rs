@@ -3,7 +3,7 @@ mod tests {
     use http::StatusCode;
     use serde_value::Value;
     use std::collections::BTreeMap;
-    use cdumay_error_http::HTTPErrorConverter;
+    use cdumay_error_http::{ErrorFormat, HTTPErrorConverter, ProblemDetails, TransportErrorKind, WebResponseError};
 
     fn sample_context() -> BTreeMap<String, Value> {
         let mut context = BTreeMap::new();
@@ -16,10 +16,28 @@ mod tests {
         let error = HTTPErrorConverter::from_u16(404, None, sample_context());
         assert_eq!(error.kind.message_id(), "HTTP-18430");
         assert_eq!(error.kind.code(), 404);
-        assert_eq!(error.message, "Not HttpRedirection302");
+        assert_eq!(error.message, "Not Found");
         assert!(error.details.unwrap().contains_key("key"));
     }
 
+    #[test]
+    fn test_informational_and_success_status_codes() {
+        let error = HTTPErrorConverter::from_u16(204, None, BTreeMap::new());
+        assert_eq!(error.kind.code(), 204);
+        assert_eq!(error.message, "No Content");
+
+        let error = HTTPErrorConverter::from_u16(100, None, BTreeMap::new());
+        assert_eq!(error.kind.code(), 100);
+        assert_eq!(error.message, "Continue");
+    }
+
+    #[test]
+    fn test_canonical_reason() {
+        assert_eq!(HTTPErrorConverter::canonical_reason(404), Some("Not Found"));
+        assert_eq!(HTTPErrorConverter::canonical_reason(200), Some("OK"));
+        assert_eq!(HTTPErrorConverter::canonical_reason(999), None);
+    }
+
     #[test]
     fn test_fallback_on_unknown_status_code() {
         let error = HTTPErrorConverter::from_u16(999, None, sample_context());
@@ -53,4 +71,126 @@ mod tests {
             let _ = HTTPErrorConverter::from_u16(code, None, BTreeMap::new());
         }
     }
+
+    #[test]
+    fn test_from_response_parses_retry_after_delta_seconds() {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(http::header::RETRY_AFTER, http::HeaderValue::from_static("120"));
+        let error = HTTPErrorConverter::from_response(StatusCode::TOO_MANY_REQUESTS, &headers, None, BTreeMap::new());
+        assert_eq!(error.details.unwrap().get("retry_after_secs"), Some(&Value::U64(120)));
+    }
+
+    #[test]
+    fn test_from_response_captures_redirect_location() {
+        let mut headers = http::HeaderMap::new();
+        headers.insert(http::header::LOCATION, http::HeaderValue::from_static("https://example.com/new"));
+        let error = HTTPErrorConverter::from_response(StatusCode::FOUND, &headers, None, BTreeMap::new());
+        assert_eq!(error.details.unwrap().get("location"), Some(&Value::String("https://example.com/new".to_string())));
+    }
+
+    #[test]
+    fn test_problem_details_standard_members() {
+        let error = HTTPErrorConverter::from_u16(404, None, BTreeMap::new());
+        let problem = ProblemDetails::from(&error);
+        assert_eq!(problem.r#type, "about:blank");
+        assert_eq!(problem.title, "Not Found");
+        assert_eq!(problem.status, 404);
+        assert_eq!(problem.detail, "Not Found");
+    }
+
+    #[test]
+    fn test_problem_details_with_type_base() {
+        let error = HTTPErrorConverter::from_u16(404, None, BTreeMap::new());
+        let problem = ProblemDetails::with_type_base(&error, Some("https://errors.example.com"));
+        assert_eq!(problem.r#type, "https://errors.example.com/HTTP-18430");
+    }
+
+    #[test]
+    fn test_problem_details_context_collision_with_reserved_member() {
+        let mut context = BTreeMap::new();
+        context.insert("status".to_string(), Value::String("bogus".to_string()));
+        context.insert("trace_id".to_string(), Value::String("abc123".to_string()));
+        let error = HTTPErrorConverter::from_u16(404, None, context);
+        let problem = ProblemDetails::from(&error);
+        assert_eq!(problem.status, 404);
+        assert_eq!(problem.extensions.get("context_status"), Some(&Value::String("bogus".to_string())));
+        assert_eq!(problem.extensions.get("trace_id"), Some(&Value::String("abc123".to_string())));
+    }
+
+    #[test]
+    fn test_to_problem_json_includes_extensions() {
+        let mut context = BTreeMap::new();
+        context.insert("trace_id".to_string(), Value::String("abc123".to_string()));
+        let error = HTTPErrorConverter::from_u16(404, None, context);
+        let json = HTTPErrorConverter::to_problem_json(&error);
+        assert!(json.contains("\"status\":404"));
+        assert!(json.contains("\"trace_id\":\"abc123\""));
+    }
+
+    #[test]
+    fn test_web_response_error_status_and_body() {
+        let error = HTTPErrorConverter::from_u16(404, None, BTreeMap::new());
+        assert_eq!(error.status_code(), StatusCode::NOT_FOUND);
+
+        let response = error.error_response();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        assert_eq!(response.headers().get(http::header::CONTENT_TYPE).unwrap(), "application/problem+json");
+        assert!(response.body().contains("\"detail\":\"Not Found\""));
+    }
+
+    #[test]
+    fn test_web_response_error_out_of_range_code_maps_to_500() {
+        let error = HTTPErrorConverter::from_u16(999, None, BTreeMap::new());
+        assert_eq!(error.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[test]
+    fn test_render_json_html_text() {
+        let error = HTTPErrorConverter::from_u16(404, None, BTreeMap::new());
+
+        let (body, content_type) = HTTPErrorConverter::render(&error, ErrorFormat::Json);
+        assert_eq!(content_type, "application/json");
+        assert!(body.contains("\"message_id\":\"HTTP-18430\""));
+
+        let (body, content_type) = HTTPErrorConverter::render(&error, ErrorFormat::Text);
+        assert_eq!(content_type, "text/plain; charset=utf-8");
+        assert_eq!(body, "code:404\nmessage_id:HTTP-18430\nmessage:Not Found");
+
+        let (body, content_type) = HTTPErrorConverter::render(&error, ErrorFormat::Html);
+        assert_eq!(content_type, "text/html; charset=utf-8");
+        assert!(body.contains("<h1>Not Found</h1>"));
+    }
+
+    #[test]
+    fn test_render_html_escapes_untrusted_message() {
+        let error = HTTPErrorConverter::from_u16(404, Some("</p><script>alert(1)</script>".to_string()), BTreeMap::new());
+        let (body, _) = HTTPErrorConverter::render(&error, ErrorFormat::Html);
+        assert!(!body.contains("<script>"));
+        assert!(body.contains("&lt;script&gt;"));
+    }
+
+    #[test]
+    fn test_negotiate_format_honors_q_weighting() {
+        assert_eq!(HTTPErrorConverter::negotiate_format("text/html"), ErrorFormat::Html);
+        assert_eq!(HTTPErrorConverter::negotiate_format("application/json"), ErrorFormat::Json);
+        assert_eq!(HTTPErrorConverter::negotiate_format("text/plain;q=0.2, text/html;q=0.9"), ErrorFormat::Html);
+        assert_eq!(HTTPErrorConverter::negotiate_format("unknown/type"), ErrorFormat::Json);
+    }
+
+    #[test]
+    fn test_from_transport_builds_expected_kind() {
+        let error = HTTPErrorConverter::from_transport(TransportErrorKind::HostLookupFailed, None, BTreeMap::new());
+        assert_eq!(error.kind.message_id(), "HTTP-05521");
+        assert_eq!(error.kind.code(), 590);
+        assert_eq!(error.message, "Host Lookup Failed");
+    }
+
+    #[test]
+    fn test_from_transport_custom_message_and_context() {
+        let mut context = BTreeMap::new();
+        context.insert("host".to_string(), Value::String("example.invalid".to_string()));
+        let error = HTTPErrorConverter::from_transport(TransportErrorKind::ConnectionFailed, Some("connection refused".to_string()), context);
+        assert_eq!(error.message, "connection refused");
+        assert_eq!(error.details.unwrap().get("host"), Some(&Value::String("example.invalid".to_string())));
+    }
 }